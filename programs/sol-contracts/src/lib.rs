@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{program::invoke, system_instruction}; // For SOL transfers
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    program::{invoke, invoke_signed},
+    system_instruction,
+}; // For SOL transfers and upgrade-authority verification
+use static_assertions::const_assert_eq;
 
 declare_id!("DV6y2NyFNh8YCPzgdHHQYPdw33BskmeeoM2xWp39xMYS");
 
@@ -20,23 +25,26 @@ pub mod agistry_registry {
         initial_registration_fee: u64,
         initial_fee_recipient: Pubkey,
     ) -> Result<()> {
-        let registry_config = &mut ctx.accounts.registry_config;
         require!(
             initial_metadata_schema_version.len() <= MAX_SCHEMA_VERSION_LENGTH,
             AgistryError::SchemaVersionTooLong
         );
 
+        let mut registry_config = ctx.accounts.registry_config.load_init()?;
         registry_config.admin = ctx.accounts.admin.key();
         registry_config.adapter_counter = 0; // Will be incremented before first use
-        registry_config.metadata_schema_version = initial_metadata_schema_version;
+        registry_config.set_metadata_schema_version(&initial_metadata_schema_version)?;
         registry_config.registration_fee = initial_registration_fee;
         registry_config.fee_recipient = initial_fee_recipient;
-        registry_config.paused = false;
+        registry_config.accumulated_fees = 0;
+        registry_config.paused = 0;
         registry_config.bump = ctx.bumps.registry_config;
+        registry_config.pending_admin = Pubkey::default();
+        registry_config.pending_admin_set = 0;
 
         emit!(RegistryInitialized {
             admin: registry_config.admin,
-            metadata_schema_version: registry_config.metadata_schema_version.clone(),
+            metadata_schema_version: initial_metadata_schema_version,
             registration_fee: registry_config.registration_fee,
             fee_recipient: registry_config.fee_recipient,
         });
@@ -45,50 +53,72 @@ pub mod agistry_registry {
 
     // Registers a new adapter.
     pub fn register_adapter(ctx: Context<RegisterAdapter>, metadata_hash: String) -> Result<()> {
-        let registry_config = &mut ctx.accounts.registry_config;
-        let adapter_account = &mut ctx.accounts.adapter_account;
-        let clock = Clock::get()?;
-
-        require!(!registry_config.paused, AgistryError::RegistryPaused);
         require!(
             metadata_hash.len() <= MAX_METADATA_HASH_LENGTH,
             AgistryError::MetadataHashTooLong
         );
-        require!(metadata_hash.len() > 0, AgistryError::MetadataHashEmpty);
-
-        // Handle registration fee
-        if registry_config.registration_fee > 0 {
+        require!(!metadata_hash.is_empty(), AgistryError::MetadataHashEmpty);
+
+        let (paused, registration_fee, schema_version) = {
+            let registry_config = ctx.accounts.registry_config.load()?;
+            (
+                registry_config.paused != 0,
+                registry_config.registration_fee,
+                registry_config.metadata_schema_version_str()?.to_string(),
+            )
+        };
+        require!(!paused, AgistryError::RegistryPaused);
+
+        // Handle registration fee: moves straight into the fee vault, never
+        // into registry_config, so protocol revenue stays isolated from the
+        // config account's rent-exempt balance.
+        if registration_fee > 0 {
             require!(
-                ctx.accounts.fee_payer.lamports() >= registry_config.registration_fee,
+                ctx.accounts.fee_payer.lamports() >= registration_fee,
                 AgistryError::InsufficientFundsForFee
             );
             invoke(
                 &system_instruction::transfer(
                     ctx.accounts.fee_payer.key,
-                    &registry_config.fee_recipient,
-                    registry_config.registration_fee,
+                    &ctx.accounts.fee_vault.key(),
+                    registration_fee,
                 ),
                 &[
                     ctx.accounts.fee_payer.to_account_info(),
-                    registry_config.to_account_info(), // Program account can also be fee recipient
+                    ctx.accounts.fee_vault.to_account_info(),
                     ctx.accounts.system_program.to_account_info(),
                 ],
             )?;
         }
 
-        registry_config.adapter_counter = registry_config
-            .adapter_counter
-            .checked_add(1)
-            .ok_or(AgistryError::NumericOverflow)?;
-        let new_adapter_id = registry_config.adapter_counter;
-
+        let clock = Clock::get()?;
+        let new_adapter_id = {
+            let mut registry_config = ctx.accounts.registry_config.load_mut()?;
+            registry_config.adapter_counter = registry_config
+                .adapter_counter
+                .checked_add(1)
+                .ok_or(AgistryError::NumericOverflow)?;
+            registry_config.accumulated_fees = registry_config
+                .accumulated_fees
+                .checked_add(registration_fee)
+                .ok_or(AgistryError::NumericOverflow)?;
+            registry_config.adapter_counter
+        };
+
+        let mut adapter_account = ctx.accounts.adapter_account.load_init()?;
         adapter_account.id = new_adapter_id;
         adapter_account.owner = ctx.accounts.owner.key();
-        adapter_account.metadata_hash = metadata_hash.clone();
-        adapter_account.status = AdapterStatus::Active;
+        adapter_account.set_metadata_hash(&metadata_hash)?;
+        adapter_account.status = AdapterStatus::Active as u8;
         adapter_account.registration_timestamp = clock.unix_timestamp;
         adapter_account.last_update_timestamp = clock.unix_timestamp;
         adapter_account.bump = ctx.bumps.adapter_account;
+        adapter_account.program_id = Pubkey::default();
+        adapter_account.verified = 0;
+        adapter_account.verified_slot = 0;
+        adapter_account.pending_owner = Pubkey::default();
+        adapter_account.pending_owner_set = 0;
+        adapter_account.set_schema_version(&schema_version)?;
 
         emit!(AdapterRegistered {
             adapter_id: new_adapter_id,
@@ -101,27 +131,36 @@ pub mod agistry_registry {
     }
 
     // Updates the metadata hash for an existing adapter.
+    //
+    // AdapterAccount is a fixed-size zero-copy account, allocated at
+    // registration with room for MAX_METADATA_HASH_LENGTH bytes of hash and
+    // MAX_SCHEMA_VERSION_LENGTH bytes of schema version, so its rent covers
+    // that full capacity whatever the actual lengths are. A new hash of any
+    // length up to MAX_METADATA_HASH_LENGTH is written in place. The account
+    // is never reallocated here: no rent is charged when the hash grows and
+    // none is refunded when it shrinks.
     pub fn update_adapter_metadata(
         ctx: Context<UpdateAdapterMetadata>,
         new_metadata_hash: String,
     ) -> Result<()> {
-        let adapter_account = &mut ctx.accounts.adapter_account;
-        let clock = Clock::get()?;
         require!(
-            !ctx.accounts.registry_config.paused,
+            ctx.accounts.registry_config.load()?.paused == 0,
             AgistryError::RegistryPaused
         );
         require!(
             new_metadata_hash.len() <= MAX_METADATA_HASH_LENGTH,
             AgistryError::MetadataHashTooLong
         );
-        require!(new_metadata_hash.len() > 0, AgistryError::MetadataHashEmpty);
+        require!(!new_metadata_hash.is_empty(), AgistryError::MetadataHashEmpty);
+
+        let clock = Clock::get()?;
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
         require!(
-            adapter_account.status == AdapterStatus::Active,
+            adapter_account.status == AdapterStatus::Active as u8,
             AgistryError::CannotUpdateDeprecatedAdapter
         );
 
-        adapter_account.metadata_hash = new_metadata_hash.clone();
+        adapter_account.set_metadata_hash(&new_metadata_hash)?;
         adapter_account.last_update_timestamp = clock.unix_timestamp;
 
         emit!(AdapterMetadataUpdated {
@@ -134,18 +173,19 @@ pub mod agistry_registry {
 
     // Deprecates an adapter.
     pub fn deprecate_adapter(ctx: Context<OperateOnAdapter>) -> Result<()> {
-        let adapter_account = &mut ctx.accounts.adapter_account;
-        let clock = Clock::get()?;
         require!(
-            !ctx.accounts.registry_config.paused,
+            ctx.accounts.registry_config.load()?.paused == 0,
             AgistryError::RegistryPaused
         );
+
+        let clock = Clock::get()?;
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
         require!(
-            adapter_account.status == AdapterStatus::Active,
+            adapter_account.status == AdapterStatus::Active as u8,
             AgistryError::AdapterAlreadyDeprecated
         );
 
-        adapter_account.status = AdapterStatus::Deprecated;
+        adapter_account.status = AdapterStatus::Deprecated as u8;
         adapter_account.last_update_timestamp = clock.unix_timestamp;
 
         emit!(AdapterStatusChanged {
@@ -156,14 +196,15 @@ pub mod agistry_registry {
         Ok(())
     }
 
-    // Transfers ownership of an adapter registration.
+    // Nominates a new owner for an adapter registration. Ownership does not
+    // move until the nominee accepts via `accept_adapter_ownership`, so a
+    // typo in `new_owner` can always be undone with `cancel_adapter_ownership_transfer`.
     pub fn transfer_adapter_ownership(
         ctx: Context<OperateOnAdapter>,
         new_owner: Pubkey,
     ) -> Result<()> {
-        let adapter_account = &mut ctx.accounts.adapter_account;
         require!(
-            !ctx.accounts.registry_config.paused,
+            ctx.accounts.registry_config.load()?.paused == 0,
             AgistryError::RegistryPaused
         );
         require!(
@@ -171,21 +212,178 @@ pub mod agistry_registry {
             AgistryError::NewOwnerCannotBeDefault
         ); // Check for zero pubkey
 
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
+        adapter_account.pending_owner = new_owner;
+        adapter_account.pending_owner_set = 1;
+
+        emit!(AdapterOwnershipTransferInitiated {
+            adapter_id: adapter_account.id,
+            current_owner: adapter_account.owner,
+            pending_owner: new_owner,
+        });
+        Ok(())
+    }
+
+    // Completes a nominated adapter ownership transfer. Must be signed by
+    // the nominee themselves, matching the pending owner exactly. Clears any
+    // existing program verification: `verified`/`program_id`/`verified_slot`
+    // attest that *this specific owner* controls the program, so a change of
+    // owner invalidates them and the new owner must call
+    // `verify_adapter_program` again before the adapter can be trusted.
+    pub fn accept_adapter_ownership(ctx: Context<AcceptAdapterOwnership>) -> Result<()> {
+        require!(
+            ctx.accounts.registry_config.load()?.paused == 0,
+            AgistryError::RegistryPaused
+        );
+
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
+        require!(
+            adapter_account.pending_owner_set == 1,
+            AgistryError::NoPendingOwner
+        );
+        require!(
+            adapter_account.pending_owner == ctx.accounts.new_owner.key(),
+            AgistryError::Unauthorized
+        );
+
         let old_owner = adapter_account.owner;
-        adapter_account.owner = new_owner;
-        // adapter_account.last_update_timestamp = clock.unix_timestamp; // Optional: decide if this updates timestamp
+        adapter_account.owner = adapter_account.pending_owner;
+        adapter_account.pending_owner = Pubkey::default();
+        adapter_account.pending_owner_set = 0;
+        adapter_account.verified = 0;
+        adapter_account.program_id = Pubkey::default();
+        adapter_account.verified_slot = 0;
 
         emit!(AdapterOwnershipTransferred {
             adapter_id: adapter_account.id,
             previous_owner: old_owner,
-            new_owner
+            new_owner: adapter_account.owner
+        });
+        Ok(())
+    }
+
+    // Revokes a pending adapter ownership transfer. Only the current owner
+    // may do this, same as initiating one.
+    pub fn cancel_adapter_ownership_transfer(ctx: Context<OperateOnAdapter>) -> Result<()> {
+        require!(
+            ctx.accounts.registry_config.load()?.paused == 0,
+            AgistryError::RegistryPaused
+        );
+
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
+        require!(
+            adapter_account.pending_owner_set == 1,
+            AgistryError::NoPendingOwner
+        );
+        adapter_account.pending_owner = Pubkey::default();
+        adapter_account.pending_owner_set = 0;
+
+        emit!(AdapterOwnershipTransferCancelled {
+            adapter_id: adapter_account.id,
+        });
+        Ok(())
+    }
+
+    // Proves that the signer registering an adapter also controls the
+    // deployed program it claims to represent, by checking that the
+    // adapter owner is the upgrade authority of that program.
+    pub fn verify_adapter_program(ctx: Context<VerifyAdapterProgram>) -> Result<()> {
+        require!(
+            ctx.accounts.registry_config.load()?.paused == 0,
+            AgistryError::RegistryPaused
+        );
+
+        let program_account_info = &ctx.accounts.program;
+        let programdata_account_info = &ctx.accounts.programdata;
+
+        require!(
+            program_account_info.owner == &bpf_loader_upgradeable::id(),
+            AgistryError::ProgramNotUpgradeable
+        );
+        require!(
+            program_account_info.executable,
+            AgistryError::ProgramNotExecutable
+        );
+
+        let program_state: UpgradeableLoaderState =
+            bincode::deserialize(&program_account_info.try_borrow_data()?)
+                .map_err(|_| error!(AgistryError::InvalidProgramAccountData))?;
+        let programdata_address = match program_state {
+            UpgradeableLoaderState::Program {
+                programdata_address,
+            } => programdata_address,
+            _ => return err!(AgistryError::InvalidProgramAccountData),
+        };
+        require!(
+            programdata_address == programdata_account_info.key(),
+            AgistryError::ProgramDataMismatch
+        );
+
+        let programdata_state: UpgradeableLoaderState =
+            bincode::deserialize(&programdata_account_info.try_borrow_data()?)
+                .map_err(|_| error!(AgistryError::InvalidProgramDataAccountData))?;
+        let (upgrade_authority_address, slot) = match programdata_state {
+            UpgradeableLoaderState::ProgramData {
+                upgrade_authority_address,
+                slot,
+            } => (upgrade_authority_address, slot),
+            _ => return err!(AgistryError::InvalidProgramDataAccountData),
+        };
+
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
+        require!(
+            upgrade_authority_address == Some(adapter_account.owner),
+            AgistryError::UpgradeAuthorityMismatch
+        );
+
+        adapter_account.program_id = program_account_info.key();
+        adapter_account.verified = 1;
+        adapter_account.verified_slot = slot;
+
+        emit!(AdapterProgramVerified {
+            adapter_id: adapter_account.id,
+            program_id: adapter_account.program_id,
+            slot,
+        });
+        Ok(())
+    }
+
+    // Upgrades an adapter from whatever metadata schema it was registered
+    // under to the registry's current one. Gated on the two versions
+    // actually differing so it can't be called as a no-op spam vector.
+    pub fn migrate_adapter(ctx: Context<OperateOnAdapter>) -> Result<()> {
+        require!(
+            ctx.accounts.registry_config.load()?.paused == 0,
+            AgistryError::RegistryPaused
+        );
+
+        let current_version = ctx
+            .accounts
+            .registry_config
+            .load()?
+            .metadata_schema_version_str()?
+            .to_string();
+
+        let mut adapter_account = ctx.accounts.adapter_account.load_mut()?;
+        let previous_version = adapter_account.schema_version_str()?.to_string();
+        require!(
+            previous_version != current_version,
+            AgistryError::AdapterAlreadyOnCurrentSchema
+        );
+
+        adapter_account.set_schema_version(&current_version)?;
+
+        emit!(AdapterMigrated {
+            adapter_id: adapter_account.id,
+            previous_schema_version: previous_version,
+            new_schema_version: current_version,
         });
         Ok(())
     }
 
     // --- Admin Functions ---
     pub fn set_pause_status(ctx: Context<AdminAction>, paused: bool) -> Result<()> {
-        ctx.accounts.registry_config.paused = paused;
+        ctx.accounts.registry_config.load_mut()?.paused = paused as u8;
         emit!(RegistryPauseStatusChanged { paused });
         Ok(())
     }
@@ -198,7 +396,10 @@ pub mod agistry_registry {
             new_version.len() <= MAX_SCHEMA_VERSION_LENGTH,
             AgistryError::SchemaVersionTooLong
         );
-        ctx.accounts.registry_config.metadata_schema_version = new_version.clone();
+        ctx.accounts
+            .registry_config
+            .load_mut()?
+            .set_metadata_schema_version(&new_version)?;
         emit!(MetadataSchemaVersionSet {
             version: new_version
         });
@@ -206,7 +407,7 @@ pub mod agistry_registry {
     }
 
     pub fn set_registration_fee(ctx: Context<AdminAction>, new_fee: u64) -> Result<()> {
-        ctx.accounts.registry_config.registration_fee = new_fee;
+        ctx.accounts.registry_config.load_mut()?.registration_fee = new_fee;
         emit!(RegistrationFeeSet { new_fee });
         Ok(())
     }
@@ -216,35 +417,127 @@ pub mod agistry_registry {
             new_recipient != Pubkey::default(),
             AgistryError::NewOwnerCannotBeDefault
         );
-        ctx.accounts.registry_config.fee_recipient = new_recipient;
+        ctx.accounts.registry_config.load_mut()?.fee_recipient = new_recipient;
         emit!(FeeRecipientSet { new_recipient });
         Ok(())
     }
 
-    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
-        let registry_config = &ctx.accounts.registry_config;
-        let fee_recipient_account_info = ctx.accounts.fee_recipient.to_account_info();
-        let registry_config_account_info = registry_config.to_account_info();
-
-        let lamports_to_withdraw = registry_config_account_info.lamports();
-        require!(lamports_to_withdraw > 0, AgistryError::NoFeesToWithdraw);
-
-        // Check if registry_config PDA is the fee_recipient
-        if registry_config.fee_recipient != registry_config_account_info.key() {
-            // Transfer from PDA to actual fee_recipient
-            **registry_config_account_info.try_borrow_mut_lamports()? -= lamports_to_withdraw;
-            **fee_recipient_account_info.try_borrow_mut_lamports()? += lamports_to_withdraw;
-
-            emit!(FeesWithdrawn {
-                recipient: registry_config.fee_recipient,
-                amount: lamports_to_withdraw,
-            });
-        } else {
-            // This case means the PDA itself is the fee recipient, which is unusual
-            // unless intended for direct program control or burning.
-            // No actual transfer needed if PDA is the target, but emit event if desired.
-            msg!("Fees are already in the designated fee_recipient (which is the registry PDA).");
-        }
+    // Nominates a new registry admin. Admin does not move until the
+    // nominee accepts via `accept_registry_admin`, so the registry can't be
+    // bricked by transferring admin to a wrong key.
+    pub fn transfer_registry_admin(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
+        require!(
+            new_admin != Pubkey::default(),
+            AgistryError::NewOwnerCannotBeDefault
+        );
+
+        let mut registry_config = ctx.accounts.registry_config.load_mut()?;
+        registry_config.pending_admin = new_admin;
+        registry_config.pending_admin_set = 1;
+
+        emit!(RegistryAdminTransferInitiated {
+            current_admin: registry_config.admin,
+            pending_admin: new_admin,
+        });
+        Ok(())
+    }
+
+    // Completes a nominated registry admin transfer. Must be signed by the
+    // nominee themselves, matching the pending admin exactly.
+    pub fn accept_registry_admin(ctx: Context<AcceptRegistryAdmin>) -> Result<()> {
+        let mut registry_config = ctx.accounts.registry_config.load_mut()?;
+        require!(
+            registry_config.pending_admin_set == 1,
+            AgistryError::NoPendingAdmin
+        );
+        require!(
+            registry_config.pending_admin == ctx.accounts.new_admin.key(),
+            AgistryError::UnauthorizedAdmin
+        );
+
+        let old_admin = registry_config.admin;
+        registry_config.admin = registry_config.pending_admin;
+        registry_config.pending_admin = Pubkey::default();
+        registry_config.pending_admin_set = 0;
+
+        emit!(RegistryAdminTransferred {
+            previous_admin: old_admin,
+            new_admin: registry_config.admin
+        });
+        Ok(())
+    }
+
+    // Revokes a pending registry admin transfer. Only the current admin
+    // may do this, same as initiating one.
+    pub fn cancel_registry_admin_transfer(ctx: Context<AdminAction>) -> Result<()> {
+        let mut registry_config = ctx.accounts.registry_config.load_mut()?;
+        require!(
+            registry_config.pending_admin_set == 1,
+            AgistryError::NoPendingAdmin
+        );
+        registry_config.pending_admin = Pubkey::default();
+        registry_config.pending_admin_set = 0;
+
+        emit!(RegistryAdminTransferCancelled {});
+        Ok(())
+    }
+
+    // Withdraws exactly `amount` (which must not exceed the accumulated
+    // protocol fees) from the fee vault to the configured fee_recipient. Partial withdrawals are
+    // supported, but a partial withdrawal that would leave the vault with a
+    // positive balance below the rent-exempt minimum is rejected: the
+    // runtime refuses to finalize a transaction that leaves any account in
+    // that state, so the remainder is required to land on exactly 0 or at
+    // least the rent-exempt minimum.
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        require!(amount > 0, AgistryError::NoFeesToWithdraw);
+
+        let mut registry_config = ctx.accounts.registry_config.load_mut()?;
+        require!(
+            amount <= registry_config.accumulated_fees,
+            AgistryError::InsufficientVaultBalance
+        );
+
+        // accumulated_fees is pure bookkeeping and knows nothing about the
+        // vault's own rent-exempt reserve. A transfer that left the vault
+        // with a positive balance below the rent-exempt minimum would be
+        // rejected by the runtime, so a withdrawal is refused unless it lands
+        // on either exactly 0 or at/above that minimum.
+        let fee_vault_info = ctx.accounts.fee_vault.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(fee_vault_info.data_len());
+        let remaining_balance = fee_vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(AgistryError::InsufficientVaultBalance)?;
+        require!(
+            remaining_balance == 0 || remaining_balance >= rent_exempt_minimum,
+            AgistryError::WithdrawalBreaksRentExemption
+        );
+
+        let fee_vault_bump = ctx.bumps.fee_vault;
+        invoke_signed(
+            &system_instruction::transfer(
+                &ctx.accounts.fee_vault.key(),
+                &ctx.accounts.fee_recipient.key(),
+                amount,
+            ),
+            &[
+                ctx.accounts.fee_vault.to_account_info(),
+                ctx.accounts.fee_recipient.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[b"fee_vault", &[fee_vault_bump]]],
+        )?;
+
+        registry_config.accumulated_fees = registry_config
+            .accumulated_fees
+            .checked_sub(amount)
+            .ok_or(AgistryError::NumericOverflow)?;
+
+        emit!(FeesWithdrawn {
+            recipient: ctx.accounts.fee_recipient.key(),
+            amount,
+        });
         Ok(())
     }
 }
@@ -260,50 +553,50 @@ pub struct InitializeRegistry<'info> {
         seeds = [b"registry_config"], // Seed for the PDA
         bump
     )]
-    pub registry_config: Account<'info, RegistryConfig>,
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
     #[account(mut)]
     pub admin: Signer<'info>, // The deployer/admin paying for initialization
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(metadata_hash: String)] // Used for space calculation if string length varies
 pub struct RegisterAdapter<'info> {
     #[account(
         init,
         payer = owner,
-        space = AdapterAccount::LEN_WITH_HASH(metadata_hash.len()),
-        seeds = [b"adapter", registry_config.adapter_counter.checked_add(1).unwrap().to_le_bytes().as_ref()], // Seed with next ID
+        space = AdapterAccount::LEN,
+        seeds = [b"adapter", registry_config.load()?.adapter_counter.checked_add(1).unwrap().to_le_bytes().as_ref()], // Seed with next ID
         bump
     )]
-    pub adapter_account: Account<'info, AdapterAccount>,
+    pub adapter_account: AccountLoader<'info, AdapterAccount>,
     #[account(
         mut,
         seeds = [b"registry_config"],
-        bump = registry_config.bump
+        bump = registry_config.load()?.bump
     )]
-    pub registry_config: Account<'info, RegistryConfig>,
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
     #[account(mut)]
     pub owner: Signer<'info>, // The one registering and initially owning the adapter
     /// CHECK: This account is used as the payer for the registration fee.
     #[account(mut)]
     pub fee_payer: AccountInfo<'info>, // Can be same as owner or different
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(new_metadata_hash: String)]
 pub struct UpdateAdapterMetadata<'info> {
     #[account(
         mut,
-        seeds = [b"adapter", adapter_account.id.to_le_bytes().as_ref()],
-        bump = adapter_account.bump,
+        seeds = [b"adapter", adapter_account.load()?.id.to_le_bytes().as_ref()],
+        bump = adapter_account.load()?.bump,
         has_one = owner @ AgistryError::Unauthorized // Constraint: signer must be owner
     )]
-    pub adapter_account: Account<'info, AdapterAccount>,
-    #[account(seeds = [b"registry_config"], bump = registry_config.bump)]
+    pub adapter_account: AccountLoader<'info, AdapterAccount>,
+    #[account(seeds = [b"registry_config"], bump = registry_config.load()?.bump)]
     // Read-only for pause check
-    pub registry_config: Account<'info, RegistryConfig>,
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
     pub owner: Signer<'info>,
 }
 
@@ -312,87 +605,209 @@ pub struct UpdateAdapterMetadata<'info> {
 pub struct OperateOnAdapter<'info> {
     #[account(
         mut,
-        seeds = [b"adapter", adapter_account.id.to_le_bytes().as_ref()],
-        bump = adapter_account.bump,
+        seeds = [b"adapter", adapter_account.load()?.id.to_le_bytes().as_ref()],
+        bump = adapter_account.load()?.bump,
         has_one = owner @ AgistryError::Unauthorized
     )]
-    pub adapter_account: Account<'info, AdapterAccount>,
-    #[account(seeds = [b"registry_config"], bump = registry_config.bump)]
-    pub registry_config: Account<'info, RegistryConfig>, // For pause check
+    pub adapter_account: AccountLoader<'info, AdapterAccount>,
+    #[account(seeds = [b"registry_config"], bump = registry_config.load()?.bump)]
+    pub registry_config: AccountLoader<'info, RegistryConfig>, // For pause check
     pub owner: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptAdapterOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [b"adapter", adapter_account.load()?.id.to_le_bytes().as_ref()],
+        bump = adapter_account.load()?.bump,
+    )]
+    pub adapter_account: AccountLoader<'info, AdapterAccount>,
+    #[account(seeds = [b"registry_config"], bump = registry_config.load()?.bump)]
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
+    pub new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptRegistryAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_config"],
+        bump = registry_config.load()?.bump
+    )]
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyAdapterProgram<'info> {
+    #[account(
+        mut,
+        seeds = [b"adapter", adapter_account.load()?.id.to_le_bytes().as_ref()],
+        bump = adapter_account.load()?.bump,
+    )]
+    pub adapter_account: AccountLoader<'info, AdapterAccount>,
+    #[account(seeds = [b"registry_config"], bump = registry_config.load()?.bump)]
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
+    /// CHECK: Ownership and `executable` are checked explicitly, and its
+    /// contents are deserialized as `UpgradeableLoaderState` below.
+    pub program: AccountInfo<'info>,
+    /// CHECK: Matched against `program`'s stored `programdata_address` and
+    /// deserialized as `UpgradeableLoaderState` below.
+    pub programdata: AccountInfo<'info>,
+}
+
 #[derive(Accounts)]
 pub struct AdminAction<'info> {
     #[account(
         mut,
         seeds = [b"registry_config"],
-        bump = registry_config.bump,
+        bump = registry_config.load()?.bump,
         has_one = admin @ AgistryError::UnauthorizedAdmin // Constraint: signer must be admin
     )]
-    pub registry_config: Account<'info, RegistryConfig>,
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
     pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct WithdrawFees<'info> {
     #[account(
-        mut, // PDA needs to be mutable to decrease its lamports
+        mut, // Mutable so accumulated_fees can be decremented
         seeds = [b"registry_config"],
-        bump = registry_config.bump,
-        constraint = registry_config.admin == admin.key() @ AgistryError::UnauthorizedAdmin
+        bump = registry_config.load()?.bump,
+        constraint = registry_config.load()?.admin == admin.key() @ AgistryError::UnauthorizedAdmin
     )]
-    pub registry_config: Account<'info, RegistryConfig>,
+    pub registry_config: AccountLoader<'info, RegistryConfig>,
+    #[account(mut, seeds = [b"fee_vault"], bump)]
+    pub fee_vault: SystemAccount<'info>,
     /// CHECK: This is the account where fees will be sent. It's validated against registry_config.fee_recipient.
-    #[account(mut, address = registry_config.fee_recipient @ AgistryError::IncorrectFeeRecipient)]
+    #[account(mut, address = registry_config.load()?.fee_recipient @ AgistryError::IncorrectFeeRecipient)]
     pub fee_recipient: AccountInfo<'info>,
     pub admin: Signer<'info>, // Admin must authorize withdrawal
+    pub system_program: Program<'info, System>,
 }
 
 // --- Account State Structs ---
-
-#[account]
+//
+// Both state accounts are zero-copy: fixed `repr(C)` layouts with every
+// field ordered so the compiler never needs to insert alignment padding of
+// its own, plus an explicit trailing `_padding` field where one is still
+// needed to round the struct up to its own alignment. This keeps
+// `repr(C)` and `repr(packed)` byte-identical, which is what
+// `AccountLoader` zero-copy deserialization relies on, and lets clients
+// read/slice the raw account bytes without a full Borsh decode.
+
+#[account(zero_copy)]
+#[repr(C)]
 pub struct RegistryConfig {
     pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub pending_admin: Pubkey, // Pubkey::default() until a transfer is nominated
     pub adapter_counter: u64, // Stores the count of registered adapters, also used for next ID
-    pub metadata_schema_version: String, // Max length defined by MAX_SCHEMA_VERSION_LENGTH
     pub registration_fee: u64, // In lamports
-    pub fee_recipient: Pubkey,
-    pub paused: bool,
-    pub bump: u8, // Bump seed for the PDA
+    pub accumulated_fees: u64, // Lamports held in the fee_vault PDA that are withdrawable
+    pub metadata_schema_version: [u8; MAX_SCHEMA_VERSION_LENGTH],
+    pub metadata_schema_version_len: u16,
+    pub paused: u8,           // 0 = false, 1 = true; bool is not a valid Pod type
+    pub bump: u8,             // Bump seed for the PDA
+    pub pending_admin_set: u8, // 0 = false, 1 = true
+    pub _padding: [u8; 7],
 }
 
+const_assert_eq!(std::mem::size_of::<RegistryConfig>(), 152);
+
 impl RegistryConfig {
-    // Calculate space: 32 (admin) + 8 (counter) + (4 + X schema_ver_len) + 8 (fee) + 32 (recipient) + 1 (paused) + 1 (bump) + 8 (discriminator)
-    const LEN_BASE: usize = 32 + 8 + 8 + 32 + 1 + 1 + 8;
-    pub const LEN: usize = Self::LEN_BASE + (4 + MAX_SCHEMA_VERSION_LENGTH);
+    // 8-byte Anchor discriminator + the fixed zero-copy struct size. No
+    // length-dependent variant exists anymore: the schema version is
+    // always stored at its maximum capacity.
+    pub const LEN: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn set_metadata_schema_version(&mut self, version: &str) -> Result<()> {
+        require!(
+            version.len() <= MAX_SCHEMA_VERSION_LENGTH,
+            AgistryError::SchemaVersionTooLong
+        );
+        self.metadata_schema_version = [0u8; MAX_SCHEMA_VERSION_LENGTH];
+        self.metadata_schema_version[..version.len()].copy_from_slice(version.as_bytes());
+        self.metadata_schema_version_len = version.len() as u16;
+        Ok(())
+    }
+
+    pub fn metadata_schema_version_str(&self) -> Result<&str> {
+        core::str::from_utf8(&self.metadata_schema_version[..self.metadata_schema_version_len as usize])
+            .map_err(|_| error!(AgistryError::InvalidUtf8))
+    }
 }
 
-#[account]
+#[account(zero_copy)]
+#[repr(C)]
 pub struct AdapterAccount {
-    pub id: u64,
     pub owner: Pubkey,
-    pub metadata_hash: String, // Max length defined by MAX_METADATA_HASH_LENGTH
-    pub status: AdapterStatus,
+    pub program_id: Pubkey, // Pubkey::default() until verify_adapter_program succeeds
+    pub pending_owner: Pubkey, // Pubkey::default() until a transfer is nominated
+    pub id: u64,
     pub registration_timestamp: i64,
     pub last_update_timestamp: i64,
-    pub bump: u8, // Bump seed for this adapter's PDA
+    pub verified_slot: u64, // Slot at which `program_id`'s upgrade authority was last confirmed
+    pub metadata_hash: [u8; MAX_METADATA_HASH_LENGTH],
+    pub metadata_hash_len: u16,
+    pub schema_version: [u8; MAX_SCHEMA_VERSION_LENGTH], // Schema this adapter's metadata conforms to
+    pub schema_version_len: u16,
+    pub status: u8,             // AdapterStatus discriminant
+    pub bump: u8,               // Bump seed for this adapter's PDA
+    pub verified: u8,           // 0 = false, 1 = true; bool is not a valid Pod type
+    pub pending_owner_set: u8,  // 0 = false, 1 = true
+    pub _padding: [u8; 6],
 }
 
+const_assert_eq!(std::mem::size_of::<AdapterAccount>(), 232);
+
 impl AdapterAccount {
-    // Calculate space: 8 (id) + 32 (owner) + (4 + X hash_len) + 1 (status enum) + 8 (reg_ts) + 8 (last_upd_ts) + 1 (bump) + 8 (discriminator)
-    const LEN_BASE: usize = 8 + 32 + 1 + 8 + 8 + 1 + 8;
-    pub fn LEN_WITH_HASH(hash_len: usize) -> usize {
-        Self::LEN_BASE + (4 + hash_len)
+    // 8-byte Anchor discriminator + the fixed zero-copy struct size. The
+    // account is always allocated at its maximum metadata-hash capacity,
+    // so there is no longer a length-dependent `LEN_WITH_HASH`.
+    pub const LEN: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn set_metadata_hash(&mut self, hash: &str) -> Result<()> {
+        require!(
+            hash.len() <= MAX_METADATA_HASH_LENGTH,
+            AgistryError::MetadataHashTooLong
+        );
+        self.metadata_hash = [0u8; MAX_METADATA_HASH_LENGTH];
+        self.metadata_hash[..hash.len()].copy_from_slice(hash.as_bytes());
+        self.metadata_hash_len = hash.len() as u16;
+        Ok(())
+    }
+
+    pub fn metadata_hash_str(&self) -> Result<&str> {
+        core::str::from_utf8(&self.metadata_hash[..self.metadata_hash_len as usize])
+            .map_err(|_| error!(AgistryError::InvalidUtf8))
+    }
+
+    pub fn set_schema_version(&mut self, version: &str) -> Result<()> {
+        require!(
+            version.len() <= MAX_SCHEMA_VERSION_LENGTH,
+            AgistryError::SchemaVersionTooLong
+        );
+        self.schema_version = [0u8; MAX_SCHEMA_VERSION_LENGTH];
+        self.schema_version[..version.len()].copy_from_slice(version.as_bytes());
+        self.schema_version_len = version.len() as u16;
+        Ok(())
+    }
+
+    pub fn schema_version_str(&self) -> Result<&str> {
+        core::str::from_utf8(&self.schema_version[..self.schema_version_len as usize])
+            .map_err(|_| error!(AgistryError::InvalidUtf8))
     }
 }
 
 // --- Enums & Custom Errors ---
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum AdapterStatus {
-    Active,
-    Deprecated,
+    Active = 0,
+    Deprecated = 1,
 }
 
 #[error_code]
@@ -423,6 +838,30 @@ pub enum AgistryError {
     IncorrectFeeRecipient,
     #[msg("No fees available to withdraw.")]
     NoFeesToWithdraw,
+    #[msg("Stored bytes are not valid UTF-8.")]
+    InvalidUtf8,
+    #[msg("The program account is not owned by the upgradeable BPF loader.")]
+    ProgramNotUpgradeable,
+    #[msg("The program account is not marked executable.")]
+    ProgramNotExecutable,
+    #[msg("The program account could not be parsed as an UpgradeableLoaderState::Program.")]
+    InvalidProgramAccountData,
+    #[msg("The programdata account could not be parsed as an UpgradeableLoaderState::ProgramData.")]
+    InvalidProgramDataAccountData,
+    #[msg("The supplied programdata account does not match the program's stored programdata_address.")]
+    ProgramDataMismatch,
+    #[msg("The program's upgrade authority does not match the adapter owner.")]
+    UpgradeAuthorityMismatch,
+    #[msg("There is no pending owner to accept or cancel.")]
+    NoPendingOwner,
+    #[msg("There is no pending admin to accept or cancel.")]
+    NoPendingAdmin,
+    #[msg("Adapter is already on the registry's current metadata schema version.")]
+    AdapterAlreadyOnCurrentSchema,
+    #[msg("The requested withdrawal amount exceeds the fee vault's accumulated fees.")]
+    InsufficientVaultBalance,
+    #[msg("This withdrawal would leave the fee vault with a positive balance below the rent-exempt minimum.")]
+    WithdrawalBreaksRentExemption,
 }
 
 // --- Events ---
@@ -449,6 +888,13 @@ pub struct AdapterMetadataUpdated {
     pub update_timestamp: i64,
 }
 
+#[event]
+pub struct AdapterMigrated {
+    pub adapter_id: u64,
+    pub previous_schema_version: String,
+    pub new_schema_version: String,
+}
+
 #[event]
 pub struct AdapterStatusChanged {
     pub adapter_id: u64,
@@ -456,6 +902,13 @@ pub struct AdapterStatusChanged {
     pub update_timestamp: i64,
 }
 
+#[event]
+pub struct AdapterOwnershipTransferInitiated {
+    pub adapter_id: u64,
+    pub current_owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
 #[event]
 pub struct AdapterOwnershipTransferred {
     pub adapter_id: u64,
@@ -463,6 +916,33 @@ pub struct AdapterOwnershipTransferred {
     pub new_owner: Pubkey,
 }
 
+#[event]
+pub struct AdapterOwnershipTransferCancelled {
+    pub adapter_id: u64,
+}
+
+#[event]
+pub struct RegistryAdminTransferInitiated {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct RegistryAdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct RegistryAdminTransferCancelled {}
+
+#[event]
+pub struct AdapterProgramVerified {
+    pub adapter_id: u64,
+    pub program_id: Pubkey,
+    pub slot: u64,
+}
+
 #[event]
 pub struct RegistryPauseStatusChanged {
     pub paused: bool,